@@ -0,0 +1,24 @@
+use crate::position::Position;
+use crate::{Backend, Color};
+
+pub struct Food {
+	pos: Position,
+}
+
+impl Food {
+	pub fn new(pos: Position) -> Self {
+		Food { pos }
+	}
+
+	pub fn pos(&self) -> Position {
+		self.pos
+	}
+
+	pub fn set_pos(&mut self, pos: Position) {
+		self.pos = pos;
+	}
+
+	pub fn draw(&self, backend: &mut dyn Backend) {
+		backend.draw_cell(self.pos, Color::RED);
+	}
+}
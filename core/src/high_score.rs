@@ -0,0 +1,18 @@
+use std::fs;
+
+const HIGH_SCORE_FILE: &str = "viper_highscore.txt";
+
+/// Reads the persisted high score, defaulting to 0 if the file is missing or
+/// unreadable (e.g. a fresh install, or a WASM build with no filesystem).
+pub fn load() -> u32 {
+	fs::read_to_string(HIGH_SCORE_FILE)
+		.ok()
+		.and_then(|contents| contents.trim().parse().ok())
+		.unwrap_or(0)
+}
+
+/// Best-effort write of the high score; failures are ignored since there's
+/// no good way to surface them from inside a game tick.
+pub fn save(score: u32) {
+	let _ = fs::write(HIGH_SCORE_FILE, score.to_string());
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+
+use crate::position::Position;
+
+const DEFAULT_LEVEL: &str = include_str!("../../levels/default.json5");
+
+/// A data-driven board layout, deserialized from a JSON5 file. Replaces what
+/// used to be the fixed `GRID_SIZE`/`CELL_SIZE`/`FPS` constants and a single
+/// hard-coded `Food`, so maps can be authored without recompiling.
+#[derive(Debug, Deserialize)]
+pub struct Level {
+	pub grid_size: (i8, i8),
+	pub cell_size: (i8, i8),
+	pub fps: u32,
+	pub start_pos: (i8, i8),
+	pub start_length: u32,
+	pub food_count: usize,
+	pub walls: Vec<(i8, i8)>,
+}
+
+impl Level {
+	pub fn from_json5(source: &str) -> Result<Self, json5::Error> {
+		json5::from_str(source)
+	}
+
+	pub fn wall_positions(&self) -> Vec<Position> {
+		self.walls.iter().map(|&pos| pos.into()).collect()
+	}
+
+	/// Cells on the board that aren't a wall. `State::with_level` panics on a
+	/// level whose walls leave fewer of these than its starting snakes and
+	/// food need.
+	pub fn free_cell_count(&self) -> usize {
+		let total = self.grid_size.0 as usize * self.grid_size.1 as usize;
+		total.saturating_sub(self.walls.len())
+	}
+}
+
+impl Default for Level {
+	/// The original 32x32, wall-free board the game shipped with.
+	fn default() -> Self {
+		Level::from_json5(DEFAULT_LEVEL).expect("bundled default level should parse")
+	}
+}
@@ -0,0 +1,78 @@
+mod food;
+mod high_score;
+mod level;
+mod position;
+mod snake;
+mod state;
+
+pub use food::Food;
+pub use level::Level;
+pub use position::{Direction, Position};
+pub use snake::{Ate, Goal, Snake};
+pub use state::State;
+
+/// A flat RGB color, independent of any particular graphics backend.
+#[derive(Clone, Copy)]
+pub struct Color {
+	pub r: u8,
+	pub g: u8,
+	pub b: u8,
+}
+
+impl Color {
+	pub const GREEN: Color = Color { r: 0, g: 255, b: 0 };
+	pub const YELLOW: Color = Color {
+		r: 255,
+		g: 255,
+		b: 0,
+	};
+	pub const RED: Color = Color { r: 255, g: 0, b: 0 };
+	pub const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+	pub const GRAY: Color = Color {
+		r: 100,
+		g: 100,
+		b: 100,
+	};
+	pub const WHITE: Color = Color {
+		r: 255,
+		g: 255,
+		b: 255,
+	};
+}
+
+/// The subset of input the game reacts to, independent of any particular
+/// windowing backend's keycode type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InputKey {
+	Up,
+	Down,
+	Left,
+	Right,
+	ToggleAutopilot,
+	TogglePause,
+	Restart,
+}
+
+/// Everything a front-end must provide so `core` can run a tick/draw loop
+/// without naming a specific graphics or windowing crate. `desktop`
+/// implements this over ggez, `web` over good-web-game/miniquad.
+///
+/// None of these methods return a `Result`: `core` has no notion of a
+/// ggez/good-web-game `GameError` to propagate. Implementations that can
+/// fail to draw (both of the above) therefore treat a failure as fatal
+/// (`expect`) rather than threading it back through `EventHandler`'s `?`.
+pub trait Backend {
+	/// Returns `true` once for every fixed-timestep tick that has elapsed
+	/// since it was last called, given the target `fps`.
+	fn should_tick(&mut self, fps: u32) -> bool;
+
+	fn clear(&mut self, color: Color);
+
+	/// Draws a single grid cell at `pos` (in cell, not pixel, coordinates).
+	fn draw_cell(&mut self, pos: Position, color: Color);
+
+	/// Draws `text` with its top-left corner at pixel coordinates `(x, y)`.
+	fn draw_text(&mut self, text: &str, x: f32, y: f32, color: Color);
+
+	fn present(&mut self);
+}
@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use oorandom::Rand32;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Position {
+	pub x: i8,
+	pub y: i8,
+}
+
+impl Position {
+	pub fn new(x: i8, y: i8) -> Self {
+		Position { x, y }
+	}
+
+	pub fn random(rng: &mut Rand32, grid_size: (i8, i8)) -> Self {
+		(
+			rng.rand_range(0..(grid_size.0 as u32)) as i8,
+			rng.rand_range(0..(grid_size.1 as u32)) as i8,
+		)
+			.into()
+	}
+
+	pub fn next(pos: Position, dir: Direction, grid_size: (i8, i8)) -> Self {
+		match dir {
+			Direction::Up => Position::new(pos.x, (pos.y - 1).rem_euclid(grid_size.1)),
+			Direction::Down => Position::new(pos.x, (pos.y + 1).rem_euclid(grid_size.1)),
+			Direction::Left => Position::new((pos.x - 1).rem_euclid(grid_size.0), pos.y),
+			Direction::Right => Position::new((pos.x + 1).rem_euclid(grid_size.0), pos.y),
+		}
+	}
+}
+
+impl From<(i8, i8)> for Position {
+	fn from(pos: (i8, i8)) -> Self {
+		Position { x: pos.0, y: pos.1 }
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+impl Direction {
+	pub fn inverse(&self) -> Self {
+		match *self {
+			Direction::Up => Direction::Down,
+			Direction::Down => Direction::Up,
+			Direction::Left => Direction::Right,
+			Direction::Right => Direction::Left,
+		}
+	}
+
+	/// Finds the direction that steps from `from` to the adjacent `to`, honoring wrap-around.
+	pub fn between(from: Position, to: Position, grid_size: (i8, i8)) -> Option<Direction> {
+		[
+			Direction::Up,
+			Direction::Down,
+			Direction::Left,
+			Direction::Right,
+		]
+		.into_iter()
+		.find(|&dir| Position::next(from, dir, grid_size) == to)
+	}
+}
+
+/// Manhattan distance between two grid cells, taking the toroidal wrap-around
+/// into account by using whichever is shorter on each axis: the direct gap or
+/// the gap through the wrap.
+pub fn wrapped_distance(a: Position, b: Position, grid_size: (i8, i8)) -> i32 {
+	let dx = (a.x - b.x).unsigned_abs() as i32;
+	let dy = (a.y - b.y).unsigned_abs() as i32;
+
+	dx.min(grid_size.0 as i32 - dx) + dy.min(grid_size.1 as i32 - dy)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct AStarNode {
+	pos: Position,
+	f: i32,
+}
+
+impl Ord for AStarNode {
+	fn cmp(&self, other: &Self) -> Ordering {
+		// Reversed so `BinaryHeap`, a max-heap, pops the lowest f first.
+		other.f.cmp(&self.f)
+	}
+}
+
+impl PartialOrd for AStarNode {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// A* search over the toroidal grid from `start` to `goal`, treating any
+/// position in `blocked` as impassable. Returns the path including both
+/// endpoints, or `None` if no path exists.
+pub fn astar(
+	start: Position,
+	goal: Position,
+	blocked: &HashSet<Position>,
+	grid_size: (i8, i8),
+) -> Option<Vec<Position>> {
+	let mut open = BinaryHeap::new();
+	let mut came_from: HashMap<Position, Position> = HashMap::new();
+	let mut g_score: HashMap<Position, i32> = HashMap::new();
+
+	g_score.insert(start, 0);
+	open.push(AStarNode {
+		pos: start,
+		f: wrapped_distance(start, goal, grid_size),
+	});
+
+	while let Some(AStarNode { pos, .. }) = open.pop() {
+		if pos == goal {
+			let mut path = vec![pos];
+			let mut current = pos;
+
+			while let Some(&prev) = came_from.get(&current) {
+				path.push(prev);
+				current = prev;
+			}
+
+			path.reverse();
+			return Some(path);
+		}
+
+		for dir in [
+			Direction::Up,
+			Direction::Down,
+			Direction::Left,
+			Direction::Right,
+		] {
+			let next = Position::next(pos, dir, grid_size);
+			if blocked.contains(&next) {
+				continue;
+			}
+
+			let tentative_g = g_score[&pos] + 1;
+			if tentative_g < *g_score.get(&next).unwrap_or(&i32::MAX) {
+				came_from.insert(next, pos);
+				g_score.insert(next, tentative_g);
+				open.push(AStarNode {
+					pos: next,
+					f: tentative_g + wrapped_distance(next, goal, grid_size),
+				});
+			}
+		}
+	}
+
+	None
+}
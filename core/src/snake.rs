@@ -0,0 +1,242 @@
+use std::collections::{HashSet, LinkedList};
+
+use crate::food::Food;
+use crate::position::{astar, wrapped_distance, Direction, Position};
+use crate::{Backend, Color};
+
+const FLEE_RADIUS: i32 = 6;
+
+#[derive(Clone, Copy)]
+struct Segment {
+	pos: Position,
+}
+
+impl Segment {
+	pub fn new(pos: Position) -> Self {
+		Segment { pos }
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Ate {
+	Itself,
+	Food,
+}
+
+/// An enemy snake's current objective: move toward the food, or away from
+/// the player's head once it gets too close.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+	Seek,
+	Flee,
+}
+
+pub struct Snake {
+	pub(crate) head: Segment,
+	pub(crate) dir: Direction,
+	body: LinkedList<Segment>,
+	pub(crate) ate: Option<Ate>,
+	prev_dir: Direction,
+	next_dir: Option<Direction>,
+	goal: Goal,
+}
+
+impl Snake {
+	pub fn new(pos: Position, length: u32, grid_size: (i8, i8)) -> Self {
+		let mut body = LinkedList::new();
+		let mut tail = pos;
+
+		for _ in 1..length.max(1) {
+			tail = Position::new((tail.x - 1).rem_euclid(grid_size.0), tail.y);
+			body.push_back(Segment::new(tail));
+		}
+
+		Snake {
+			head: Segment::new(pos),
+			dir: Direction::Right,
+			body,
+			ate: None,
+			prev_dir: Direction::Right,
+			next_dir: None,
+			goal: Goal::Seek,
+		}
+	}
+
+	pub fn queue_dir(&mut self, dir: Direction) {
+		if self.dir != self.prev_dir && dir.inverse() != self.dir {
+			self.next_dir = Some(dir);
+		} else if dir.inverse() != self.prev_dir {
+			self.dir = dir;
+		}
+	}
+
+	fn eats(&self, foods: &[Food]) -> Option<usize> {
+		foods.iter().position(|food| self.head.pos == food.pos())
+	}
+
+	fn eats_self(&self) -> bool {
+		for seg in self.body.iter() {
+			if self.head.pos == seg.pos {
+				return true;
+			}
+		}
+
+		false
+	}
+
+	/// All cells currently occupied by this snake's body (not the head).
+	pub fn occupied(&self) -> HashSet<Position> {
+		self.body.iter().map(|seg| seg.pos).collect()
+	}
+
+	/// Picks the next direction toward the nearest food via A*, falling back
+	/// to any safe neighbor if the snake is boxed in. `obstacles` are cells
+	/// occupied by other snakes, so autopilot won't path the player through
+	/// a live enemy.
+	pub fn autopilot_dir(
+		&self,
+		foods: &[Food],
+		obstacles: &HashSet<Position>,
+		walls: &HashSet<Position>,
+		grid_size: (i8, i8),
+	) -> Direction {
+		let mut blocked = self.occupied();
+		blocked.extend(obstacles.iter().copied());
+		blocked.extend(walls.iter().copied());
+
+		let goal = foods
+			.iter()
+			.map(|food| food.pos())
+			.min_by_key(|&pos| wrapped_distance(self.head.pos, pos, grid_size));
+
+		if let Some(goal) = goal {
+			if let Some(path) = astar(self.head.pos, goal, &blocked, grid_size) {
+				if path.len() >= 2 {
+					if let Some(dir) = Direction::between(path[0], path[1], grid_size) {
+						if dir != self.dir.inverse() {
+							return dir;
+						}
+					}
+				}
+			}
+		}
+
+		for dir in [
+			self.dir,
+			Direction::Up,
+			Direction::Down,
+			Direction::Left,
+			Direction::Right,
+		] {
+			if dir == self.dir.inverse() {
+				continue;
+			}
+
+			if !blocked.contains(&Position::next(self.head.pos, dir, grid_size)) {
+				return dir;
+			}
+		}
+
+		self.dir
+	}
+
+	/// Updates this enemy's goal: flee the player's head once it strays
+	/// within `FLEE_RADIUS`, otherwise seek the nearest food.
+	pub fn update_goal(&mut self, player_head: Position, grid_size: (i8, i8)) {
+		self.goal = if wrapped_distance(self.head.pos, player_head, grid_size) <= FLEE_RADIUS {
+			Goal::Flee
+		} else {
+			Goal::Seek
+		};
+	}
+
+	/// Picks the next direction by greedily reducing (`Seek`) or increasing
+	/// (`Flee`) distance to the target, never reversing into itself.
+	pub fn enemy_dir(&self, foods: &[Food], player_head: Position, grid_size: (i8, i8)) -> Direction {
+		let target = match self.goal {
+			Goal::Seek => foods
+				.iter()
+				.map(|food| food.pos())
+				.min_by_key(|&pos| wrapped_distance(self.head.pos, pos, grid_size))
+				.unwrap_or(self.head.pos),
+			Goal::Flee => player_head,
+		};
+
+		let mut best = self.dir;
+		let mut best_dist = wrapped_distance(Position::next(self.head.pos, self.dir, grid_size), target, grid_size);
+
+		for dir in [
+			Direction::Up,
+			Direction::Down,
+			Direction::Left,
+			Direction::Right,
+		] {
+			if dir == self.dir.inverse() {
+				continue;
+			}
+
+			let dist = wrapped_distance(Position::next(self.head.pos, dir, grid_size), target, grid_size);
+			let better = match self.goal {
+				Goal::Seek => dist < best_dist,
+				Goal::Flee => dist > best_dist,
+			};
+
+			if better {
+				best = dir;
+				best_dist = dist;
+			}
+		}
+
+		best
+	}
+
+	/// Advances the snake one tick. `obstacles` are cells occupied by other
+	/// snakes (head or body) and `walls` are level obstacles; colliding with
+	/// either is treated like a self-collision.
+	pub fn update(
+		&mut self,
+		foods: &[Food],
+		obstacles: &HashSet<Position>,
+		walls: &HashSet<Position>,
+		grid_size: (i8, i8),
+	) -> Option<usize> {
+		if self.prev_dir == self.dir && self.next_dir.is_some() {
+			self.dir = self.next_dir.unwrap();
+			self.next_dir = None;
+		}
+
+		let new_head = Segment::new(Position::next(self.head.pos, self.dir, grid_size));
+		self.body.push_front(self.head);
+		self.head = new_head;
+
+		let eaten = self.eats(foods);
+
+		if self.eats_self() || obstacles.contains(&self.head.pos) || walls.contains(&self.head.pos) {
+			self.ate = Some(Ate::Itself);
+		} else if eaten.is_some() {
+			self.ate = Some(Ate::Food);
+		} else {
+			self.ate = None;
+		}
+
+		if self.ate.is_none() {
+			self.body.pop_back();
+		}
+
+		self.prev_dir = self.dir;
+
+		if self.ate == Some(Ate::Food) {
+			eaten
+		} else {
+			None
+		}
+	}
+
+	pub fn draw(&self, backend: &mut dyn Backend, color: Color) {
+		for seg in self.body.iter() {
+			backend.draw_cell(seg.pos, color);
+		}
+
+		backend.draw_cell(self.head.pos, color);
+	}
+}
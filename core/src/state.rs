@@ -0,0 +1,285 @@
+use std::collections::HashSet;
+
+use oorandom::Rand32;
+
+use crate::food::Food;
+use crate::high_score;
+use crate::level::Level;
+use crate::position::{Direction, Position};
+use crate::snake::{Ate, Snake};
+use crate::{Backend, Color, InputKey};
+
+const ENEMY_COUNT: usize = 2;
+
+/// Picks a random cell outside of `blocked`, so food and snakes never spawn
+/// on a wall or each other — walking onto either is a fatal collision, and a
+/// food item there would be an uncollectable, invisible death trap. Falls
+/// back to a deterministic scan of the board after a generous number of
+/// random misses, so a pathological custom level can't spin this forever.
+fn random_free_pos(rng: &mut Rand32, grid_size: (i8, i8), blocked: &HashSet<Position>) -> Position {
+	let cell_count = grid_size.0 as u32 * grid_size.1 as u32;
+
+	for _ in 0..cell_count.max(1) {
+		let pos = Position::random(rng, grid_size);
+		if !blocked.contains(&pos) {
+			return pos;
+		}
+	}
+
+	(0..grid_size.0)
+		.flat_map(|x| (0..grid_size.1).map(move |y| Position::new(x, y)))
+		.find(|pos| !blocked.contains(pos))
+		.expect("level leaves no free cell to spawn into")
+}
+
+pub struct State {
+	snake: Snake,
+	enemies: Vec<Snake>,
+	foods: Vec<Food>,
+	walls: HashSet<Position>,
+	grid_size: (i8, i8),
+	cell_size: (i8, i8),
+	fps: u32,
+	start_pos: Position,
+	start_length: u32,
+	food_count: usize,
+	game_over: bool,
+	paused: bool,
+	rng: Rand32,
+	autopilot: bool,
+	score: u32,
+	high_score: u32,
+}
+
+impl State {
+	pub fn new() -> Self {
+		Self::with_level(Level::default())
+	}
+
+	pub fn with_level(level: Level) -> Self {
+		let needed = level.food_count.max(1) + (ENEMY_COUNT + 1) * 2;
+		assert!(
+			level.free_cell_count() >= needed,
+			"level's walls leave only {} free cells, need at least {needed} for the starting snakes and food",
+			level.free_cell_count(),
+		);
+
+		let walls: HashSet<Position> = level.wall_positions().into_iter().collect();
+		let start_pos: Position = level.start_pos.into();
+		assert!(
+			!walls.contains(&start_pos),
+			"level's start_pos {start_pos:?} is on a wall",
+		);
+
+		let mut seed: [u8; 8] = [0; 8];
+		getrandom::getrandom(&mut seed[..]).expect("could not create RNG seed");
+		let mut rng = Rand32::new(u64::from_ne_bytes(seed));
+
+		let snake = Snake::new(start_pos, level.start_length, level.grid_size);
+
+		let mut blocked = walls.clone();
+		blocked.insert(snake.head.pos);
+		blocked.extend(snake.occupied());
+
+		let mut enemies = Vec::with_capacity(ENEMY_COUNT);
+		for _ in 0..ENEMY_COUNT {
+			let pos = random_free_pos(&mut rng, level.grid_size, &blocked);
+			let enemy = Snake::new(pos, 2, level.grid_size);
+			blocked.insert(enemy.head.pos);
+			blocked.extend(enemy.occupied());
+			enemies.push(enemy);
+		}
+
+		let foods = (0..level.food_count.max(1))
+			.map(|_| Food::new(random_free_pos(&mut rng, level.grid_size, &blocked)))
+			.collect();
+
+		State {
+			snake,
+			enemies,
+			foods,
+			walls,
+			grid_size: level.grid_size,
+			cell_size: level.cell_size,
+			fps: level.fps,
+			start_pos: level.start_pos.into(),
+			start_length: level.start_length,
+			food_count: level.food_count,
+			game_over: false,
+			paused: false,
+			rng,
+			autopilot: false,
+			score: 0,
+			high_score: high_score::load(),
+		}
+	}
+
+	pub fn grid_size(&self) -> (i8, i8) {
+		self.grid_size
+	}
+
+	pub fn cell_size(&self) -> (i8, i8) {
+		self.cell_size
+	}
+
+	pub fn score(&self) -> u32 {
+		self.score
+	}
+
+	pub fn game_over(&self) -> bool {
+		self.game_over
+	}
+
+	pub fn handle_key(&mut self, key: InputKey) {
+		match key {
+			InputKey::ToggleAutopilot => self.autopilot = !self.autopilot,
+			InputKey::TogglePause => self.paused = !self.paused,
+			InputKey::Restart => self.restart(),
+			InputKey::Up => self.snake.queue_dir(Direction::Up),
+			InputKey::Down => self.snake.queue_dir(Direction::Down),
+			InputKey::Left => self.snake.queue_dir(Direction::Left),
+			InputKey::Right => self.snake.queue_dir(Direction::Right),
+		}
+	}
+
+	/// Rebuilds a fresh snake, enemies and food without exiting the process,
+	/// clearing `game_over` and the in-run score. The high score carries over.
+	fn restart(&mut self) {
+		self.snake = Snake::new(self.start_pos, self.start_length, self.grid_size);
+
+		let mut blocked = self.walls.clone();
+		blocked.insert(self.snake.head.pos);
+		blocked.extend(self.snake.occupied());
+
+		self.enemies = Vec::with_capacity(ENEMY_COUNT);
+		for _ in 0..ENEMY_COUNT {
+			let pos = random_free_pos(&mut self.rng, self.grid_size, &blocked);
+			let enemy = Snake::new(pos, 2, self.grid_size);
+			blocked.insert(enemy.head.pos);
+			blocked.extend(enemy.occupied());
+			self.enemies.push(enemy);
+		}
+
+		self.foods = (0..self.food_count.max(1))
+			.map(|_| Food::new(random_free_pos(&mut self.rng, self.grid_size, &blocked)))
+			.collect();
+		self.game_over = false;
+		self.paused = false;
+		self.score = 0;
+	}
+
+	/// Every cell a fresh food spawn must avoid: walls and all snake bodies.
+	fn occupied_cells(&self) -> HashSet<Position> {
+		let mut blocked = self.walls.clone();
+		blocked.insert(self.snake.head.pos);
+		blocked.extend(self.snake.occupied());
+		for enemy in &self.enemies {
+			blocked.insert(enemy.head.pos);
+			blocked.extend(enemy.occupied());
+		}
+		blocked
+	}
+
+	fn respawn_food(&mut self, index: usize) {
+		let blocked = self.occupied_cells();
+		self.foods[index] = Food::new(random_free_pos(&mut self.rng, self.grid_size, &blocked));
+	}
+
+	/// Advances the game by one frame, running as many fixed-timestep ticks
+	/// as `backend.should_tick` reports have elapsed.
+	pub fn update(&mut self, backend: &mut dyn Backend) {
+		while backend.should_tick(self.fps) {
+			if self.game_over || self.paused {
+				continue;
+			}
+
+			let mut enemy_cells: HashSet<Position> =
+				self.enemies.iter().flat_map(|e| e.occupied()).collect();
+			enemy_cells.extend(self.enemies.iter().map(|e| e.head.pos));
+
+			if self.autopilot {
+				self.snake.dir = self
+					.snake
+					.autopilot_dir(&self.foods, &enemy_cells, &self.walls, self.grid_size);
+			}
+
+			if let Some(index) =
+				self.snake
+					.update(&self.foods, &enemy_cells, &self.walls, self.grid_size)
+			{
+				self.respawn_food(index);
+				self.score += 1;
+			}
+
+			if self.snake.ate == Some(Ate::Itself) {
+				self.game_over = true;
+				self.high_score = self.high_score.max(self.score);
+				high_score::save(self.high_score);
+			}
+
+			for i in 0..self.enemies.len() {
+				self.enemies[i].update_goal(self.snake.head.pos, self.grid_size);
+				self.enemies[i].dir =
+					self.enemies[i].enemy_dir(&self.foods, self.snake.head.pos, self.grid_size);
+
+				let mut obstacles = self.snake.occupied();
+				obstacles.insert(self.snake.head.pos);
+				for (j, other) in self.enemies.iter().enumerate() {
+					if j != i {
+						obstacles.extend(other.occupied());
+						obstacles.insert(other.head.pos);
+					}
+				}
+
+				if let Some(index) =
+					self.enemies[i].update(&self.foods, &obstacles, &self.walls, self.grid_size)
+				{
+					self.respawn_food(index);
+				}
+
+				if self.enemies[i].ate == Some(Ate::Itself) {
+					self.score += 1;
+				}
+			}
+
+			self.enemies.retain(|e| e.ate != Some(Ate::Itself));
+		}
+	}
+
+	pub fn draw(&self, backend: &mut dyn Backend) {
+		backend.clear(Color::BLACK);
+
+		for &wall in &self.walls {
+			backend.draw_cell(wall, Color::GRAY);
+		}
+
+		self.snake.draw(backend, Color::GREEN);
+		for enemy in &self.enemies {
+			enemy.draw(backend, Color::YELLOW);
+		}
+		for food in &self.foods {
+			food.draw(backend);
+		}
+
+		backend.draw_text(
+			&format!("Score: {}  Best: {}", self.score, self.high_score),
+			4.0,
+			4.0,
+			Color::WHITE,
+		);
+
+		if self.game_over {
+			backend.draw_text("Game Over - press R to restart", 4.0, 20.0, Color::WHITE);
+		} else if self.paused {
+			backend.draw_text("Paused", 4.0, 20.0, Color::WHITE);
+		}
+
+		backend.present();
+	}
+}
+
+impl Default for State {
+	fn default() -> Self {
+		Self::new()
+	}
+}
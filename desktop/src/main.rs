@@ -0,0 +1,174 @@
+use std::{env, fs};
+
+use ggez::conf::WindowMode;
+use ggez::event::{self, EventHandler, KeyCode};
+use ggez::graphics::{self, DrawParam, Image, InstanceArray, Text};
+use ggez::mint::Point2;
+use ggez::{timer, Context, ContextBuilder, GameError, GameResult};
+
+use viper_core::{Backend, Color, InputKey, Level, Position, State};
+
+fn to_ggez_color(color: Color) -> graphics::Color {
+	graphics::Color::from_rgb(color.r, color.g, color.b)
+}
+
+fn from_keycode(key: KeyCode) -> Option<InputKey> {
+	match key {
+		KeyCode::Up => Some(InputKey::Up),
+		KeyCode::Down => Some(InputKey::Down),
+		KeyCode::Left => Some(InputKey::Left),
+		KeyCode::Right => Some(InputKey::Right),
+		KeyCode::A => Some(InputKey::ToggleAutopilot),
+		KeyCode::P => Some(InputKey::TogglePause),
+		KeyCode::R => Some(InputKey::Restart),
+		_ => None,
+	}
+}
+
+/// Implements `viper_core::Backend` over a borrowed ggez `Context` plus the
+/// `App`-owned unit-square image and instance array, keeping every
+/// ggez-specific call (`InstanceArray`, `graphics::draw`,
+/// `timer::check_update_time`) out of `core`. Cells are accumulated into
+/// `instances` by `draw_cell` and flushed with a single draw call in
+/// `present`, rather than issuing one draw call per cell per frame.
+struct GgezBackend<'a> {
+	ctx: &'a mut Context,
+	instances: &'a mut InstanceArray,
+	cell_size: (i8, i8),
+}
+
+impl<'a> GgezBackend<'a> {
+	fn new(ctx: &'a mut Context, instances: &'a mut InstanceArray, cell_size: (i8, i8)) -> Self {
+		GgezBackend {
+			ctx,
+			instances,
+			cell_size,
+		}
+	}
+
+	fn position_to_dest(&self, pos: Position) -> [f32; 2] {
+		[
+			pos.x as f32 * self.cell_size.0 as f32,
+			pos.y as f32 * self.cell_size.1 as f32,
+		]
+	}
+}
+
+impl Backend for GgezBackend<'_> {
+	fn should_tick(&mut self, fps: u32) -> bool {
+		timer::check_update_time(self.ctx, fps)
+	}
+
+	fn clear(&mut self, color: Color) {
+		graphics::clear(self.ctx, to_ggez_color(color));
+		self.instances.clear();
+	}
+
+	fn draw_cell(&mut self, pos: Position, color: Color) {
+		self.instances.push(
+			DrawParam::default()
+				.dest(self.position_to_dest(pos))
+				.scale([self.cell_size.0 as f32, self.cell_size.1 as f32])
+				.color(to_ggez_color(color)),
+		);
+	}
+
+	fn draw_text(&mut self, text: &str, x: f32, y: f32, color: Color) {
+		let text = Text::new(text);
+		graphics::draw(
+			self.ctx,
+			&text,
+			DrawParam::default()
+				.dest(Point2 { x, y })
+				.color(to_ggez_color(color)),
+		)
+		.expect("failed to draw text");
+	}
+
+	fn present(&mut self) {
+		graphics::draw(self.ctx, self.instances, DrawParam::default())
+			.expect("failed to draw instances");
+		graphics::present(self.ctx).expect("failed to present frame");
+		timer::yield_now();
+	}
+}
+
+struct App {
+	state: State,
+	// A single reusable unit-square sprite, scaled and tinted per cell and
+	// batched through `instances` so growing the snake to hundreds of
+	// segments doesn't cost a fresh mesh allocation per cell per frame.
+	instances: InstanceArray,
+	cell_size: (i8, i8),
+}
+
+impl App {
+	fn new(ctx: &mut Context, state: State) -> GameResult<Self> {
+		let unit_square = Image::solid(ctx, 1, graphics::Color::WHITE)?;
+		let cell_size = state.cell_size();
+
+		Ok(App {
+			state,
+			instances: InstanceArray::new(ctx, unit_square),
+			cell_size,
+		})
+	}
+}
+
+impl EventHandler<GameError> for App {
+	fn update(&mut self, ctx: &mut Context) -> GameResult {
+		let mut backend = GgezBackend::new(ctx, &mut self.instances, self.cell_size);
+		self.state.update(&mut backend);
+
+		Ok(())
+	}
+
+	fn draw(&mut self, ctx: &mut Context) -> GameResult {
+		let mut backend = GgezBackend::new(ctx, &mut self.instances, self.cell_size);
+		self.state.draw(&mut backend);
+
+		Ok(())
+	}
+
+	fn key_down_event(
+		&mut self,
+		_ctx: &mut Context,
+		keycode: KeyCode,
+		_keymods: event::KeyMods,
+		_repeat: bool,
+	) {
+		if let Some(key) = from_keycode(keycode) {
+			self.state.handle_key(key);
+		}
+	}
+}
+
+/// Loads the level named on the command line, falling back to the bundled
+/// default so `cargo run` with no arguments still works.
+fn load_state() -> State {
+	let Some(path) = env::args().nth(1) else {
+		return State::new();
+	};
+
+	let source = fs::read_to_string(&path).unwrap_or_else(|err| panic!("could not read level {path}: {err}"));
+	let level = Level::from_json5(&source).unwrap_or_else(|err| panic!("invalid level {path}: {err}"));
+
+	State::with_level(level)
+}
+
+fn main() -> GameResult {
+	let state = load_state();
+	let (grid_size, cell_size) = (state.grid_size(), state.cell_size());
+	let screen_size = (
+		cell_size.0 as f32 * grid_size.0 as f32,
+		cell_size.1 as f32 * grid_size.1 as f32,
+	);
+
+	let (mut ctx, event_loop) = ContextBuilder::new("viper", "mathletedev")
+		.window_mode(WindowMode::default().dimensions(screen_size.0, screen_size.1))
+		.build()?;
+
+	let app = App::new(&mut ctx, state)?;
+
+	event::run(ctx, event_loop, app);
+}
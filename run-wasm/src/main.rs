@@ -0,0 +1,5 @@
+//! Builds `web` for `wasm32-unknown-unknown` and serves it locally. Run via
+//! the `cargo run-wasm` alias rather than directly.
+fn main() {
+	cargo_run_wasm::run_wasm_with_css("body { background-color: black; margin: 0px; }");
+}
@@ -0,0 +1,153 @@
+// `good_web_game` mirrors ggez's API closely enough that this front-end is a
+// near copy of `desktop`, swapped onto a miniquad/WASM context instead of a
+// native one.
+use good_web_game as ggez;
+
+use ggez::event::{self, EventHandler, KeyCode};
+use ggez::graphics::{self, DrawParam, Image, InstanceArray, Text};
+use ggez::mint::Point2;
+use ggez::{conf, timer, Context, GameError, GameResult};
+
+use viper_core::{Backend, Color, InputKey, Position, State};
+
+fn to_ggez_color(color: Color) -> graphics::Color {
+	graphics::Color::from_rgb(color.r, color.g, color.b)
+}
+
+fn from_keycode(key: KeyCode) -> Option<InputKey> {
+	match key {
+		KeyCode::Up => Some(InputKey::Up),
+		KeyCode::Down => Some(InputKey::Down),
+		KeyCode::Left => Some(InputKey::Left),
+		KeyCode::Right => Some(InputKey::Right),
+		KeyCode::A => Some(InputKey::ToggleAutopilot),
+		KeyCode::P => Some(InputKey::TogglePause),
+		KeyCode::R => Some(InputKey::Restart),
+		_ => None,
+	}
+}
+
+/// Implements `viper_core::Backend` over a borrowed good-web-game `Context`
+/// plus the `App`-owned instance array. Mirrors `desktop`'s `GgezBackend`
+/// one for one since the two crates' drawing APIs line up, right down to
+/// batching cells into a single draw call in `present`. The browser build
+/// always runs the bundled default level; there's no filesystem to load a
+/// custom one from.
+struct GwgBackend<'a> {
+	ctx: &'a mut Context,
+	instances: &'a mut InstanceArray,
+	cell_size: (i8, i8),
+}
+
+impl<'a> GwgBackend<'a> {
+	fn new(ctx: &'a mut Context, instances: &'a mut InstanceArray, cell_size: (i8, i8)) -> Self {
+		GwgBackend {
+			ctx,
+			instances,
+			cell_size,
+		}
+	}
+
+	fn position_to_dest(&self, pos: Position) -> [f32; 2] {
+		[
+			pos.x as f32 * self.cell_size.0 as f32,
+			pos.y as f32 * self.cell_size.1 as f32,
+		]
+	}
+}
+
+impl Backend for GwgBackend<'_> {
+	fn should_tick(&mut self, fps: u32) -> bool {
+		timer::check_update_time(self.ctx, fps)
+	}
+
+	fn clear(&mut self, color: Color) {
+		graphics::clear(self.ctx, to_ggez_color(color));
+		self.instances.clear();
+	}
+
+	fn draw_cell(&mut self, pos: Position, color: Color) {
+		self.instances.push(
+			DrawParam::default()
+				.dest(self.position_to_dest(pos))
+				.scale([self.cell_size.0 as f32, self.cell_size.1 as f32])
+				.color(to_ggez_color(color)),
+		);
+	}
+
+	fn draw_text(&mut self, text: &str, x: f32, y: f32, color: Color) {
+		let text = Text::new(text);
+		graphics::draw(
+			self.ctx,
+			&text,
+			DrawParam::default()
+				.dest(Point2 { x, y })
+				.color(to_ggez_color(color)),
+		)
+		.expect("failed to draw text");
+	}
+
+	fn present(&mut self) {
+		graphics::draw(self.ctx, self.instances, DrawParam::default())
+			.expect("failed to draw instances");
+		graphics::present(self.ctx).expect("failed to present frame");
+	}
+}
+
+struct App {
+	state: State,
+	instances: InstanceArray,
+	cell_size: (i8, i8),
+}
+
+impl App {
+	fn new(ctx: &mut Context) -> GameResult<Self> {
+		let unit_square = Image::solid(ctx, 1, graphics::Color::WHITE)?;
+		let state = State::new();
+		let cell_size = state.cell_size();
+
+		Ok(App {
+			state,
+			instances: InstanceArray::new(ctx, unit_square),
+			cell_size,
+		})
+	}
+}
+
+impl EventHandler<GameError> for App {
+	fn update(&mut self, ctx: &mut Context) -> GameResult {
+		let mut backend = GwgBackend::new(ctx, &mut self.instances, self.cell_size);
+		self.state.update(&mut backend);
+
+		Ok(())
+	}
+
+	fn draw(&mut self, ctx: &mut Context) -> GameResult {
+		let mut backend = GwgBackend::new(ctx, &mut self.instances, self.cell_size);
+		self.state.draw(&mut backend);
+
+		Ok(())
+	}
+
+	fn key_down_event(&mut self, _ctx: &mut Context, keycode: KeyCode, _keymods: event::KeyMods) {
+		if let Some(key) = from_keycode(keycode) {
+			self.state.handle_key(key);
+		}
+	}
+}
+
+fn main() -> GameResult {
+	let default_level = viper_core::Level::default();
+	let screen_size = (
+		default_level.cell_size.0 as f32 * default_level.grid_size.0 as f32,
+		default_level.cell_size.1 as f32 * default_level.grid_size.1 as f32,
+	);
+
+	ggez::start(
+		conf::Conf::default()
+			.window_title("viper".to_owned())
+			.window_width(screen_size.0 as i32)
+			.window_height(screen_size.1 as i32),
+		|ctx, _quad_ctx| Box::new(App::new(ctx).expect("failed to initialize app")),
+	)
+}